@@ -1,14 +1,20 @@
+use regex::Regex;
 use serde::{Deserialize, Serialize};
 use std::{
+    collections::HashSet,
     fs,
-    io::Write,
-    path::PathBuf,
+    io::{Read, Write},
+    path::{Path, PathBuf},
     process::{Command, Stdio},
-    time::{SystemTime, UNIX_EPOCH},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    time::{Duration, SystemTime, UNIX_EPOCH},
 };
 use tauri::{AppHandle, Manager};
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 struct ManDocumentPayload {
     query: String,
@@ -16,6 +22,42 @@ struct ManDocumentPayload {
     source: String,
     raw_text: String,
     fetched_at: String,
+    rendered_html: Option<String>,
+    references: Vec<ManReference>,
+    examples: Vec<TldrExample>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ManCacheSidecar {
+    fetched_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ManReference {
+    name: String,
+    section: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ManSearchResult {
+    name: String,
+    section: String,
+    summary: String,
+}
+
+const MAX_SEARCH_RESULTS: usize = 200;
+const DEFAULT_CACHE_TTL_SECONDS: u64 = 7 * 24 * 60 * 60;
+const MIN_CACHE_TTL_SECONDS: u64 = 60;
+const CHEAT_SH_TIMEOUT_SECONDS: u64 = 5;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TldrExample {
+    description: String,
+    command: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -29,12 +71,16 @@ struct WindowState {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[serde(rename_all = "camelCase", default)]
 struct ViewerSettings {
     theme: String,
     font_scale: f64,
     minimap_visible: bool,
     last_search_mode: String,
+    html_rendering: bool,
+    cache_enabled: bool,
+    cache_ttl_seconds: u64,
+    cheat_sh_fallback: bool,
     window_state: WindowState,
 }
 
@@ -55,6 +101,10 @@ struct ViewerSettingsPatch {
     font_scale: Option<f64>,
     minimap_visible: Option<bool>,
     last_search_mode: Option<String>,
+    html_rendering: Option<bool>,
+    cache_enabled: Option<bool>,
+    cache_ttl_seconds: Option<u64>,
+    cheat_sh_fallback: Option<bool>,
     window_state: Option<WindowStatePatch>,
 }
 
@@ -77,21 +127,54 @@ impl Default for ViewerSettings {
             font_scale: 1.0,
             minimap_visible: true,
             last_search_mode: "find".to_string(),
+            html_rendering: false,
+            cache_enabled: true,
+            cache_ttl_seconds: DEFAULT_CACHE_TTL_SECONDS,
+            cheat_sh_fallback: false,
             window_state: WindowState::default(),
         }
     }
 }
 
 #[tauri::command]
-fn load_man_page(input: String) -> Result<ManDocumentPayload, String> {
+fn load_man_page(app: AppHandle, input: String) -> Result<ManDocumentPayload, String> {
     let (section, topic) = parse_man_input(&input)?;
-    let raw_text = run_man_command(section.as_deref(), &topic)?;
+    let settings = read_settings(&app)?;
+    let render_mode = if settings.html_rendering {
+        "html"
+    } else {
+        "plain"
+    };
+    let key = man_cache_key(section.as_deref(), &topic, render_mode);
+
+    if settings.cache_enabled {
+        if let Some(cached) = read_man_cache(&app, &key, settings.cache_ttl_seconds) {
+            return Ok(cached);
+        }
+    }
+
+    let raw_text = match run_man_command(section.as_deref(), &topic) {
+        Ok(raw_text) => raw_text,
+        Err(error) => {
+            if settings.cheat_sh_fallback && is_man_not_found_error(&error) {
+                return fetch_cheat_sheet(&topic);
+            }
+            return Err(error);
+        }
+    };
 
     if raw_text.trim().is_empty() {
         return Err("The man command returned no content.".to_string());
     }
 
-    Ok(ManDocumentPayload {
+    let rendered_html = if settings.html_rendering {
+        render_man_html(section.as_deref(), &topic).ok()
+    } else {
+        None
+    };
+    let references = extract_references(&raw_text, &topic);
+
+    let payload = ManDocumentPayload {
         query: if let Some(section) = section {
             format!("{section} {topic}")
         } else {
@@ -101,9 +184,99 @@ fn load_man_page(input: String) -> Result<ManDocumentPayload, String> {
         source: "system-man".to_string(),
         raw_text,
         fetched_at: current_timestamp(),
+        rendered_html,
+        references,
+        examples: Vec::new(),
+    };
+
+    let render_failed = settings.html_rendering && payload.rendered_html.is_none();
+    if settings.cache_enabled && !render_failed {
+        let _ = write_man_cache(&app, &key, &payload);
+    }
+
+    Ok(payload)
+}
+
+#[tauri::command]
+fn load_cheat_sheet(topic: String) -> Result<ManDocumentPayload, String> {
+    let topic = topic.trim();
+    if topic.is_empty() {
+        return Err("Please provide a topic to look up.".to_string());
+    }
+
+    fetch_cheat_sheet(topic)
+}
+
+#[tauri::command]
+fn clear_man_cache(app: AppHandle) -> Result<(), String> {
+    let dir = man_cache_dir(&app)?;
+
+    if dir.exists() {
+        fs::remove_dir_all(&dir)
+            .map_err(|error| format!("Failed to clear man page cache: {error}"))?;
+    }
+
+    Ok(())
+}
+
+#[tauri::command]
+fn load_tldr_page(
+    app: AppHandle,
+    topic: String,
+    platform: Option<String>,
+) -> Result<ManDocumentPayload, String> {
+    let topic = topic.trim();
+    if topic.is_empty() {
+        return Err("Please provide a topic to look up.".to_string());
+    }
+
+    let platform = platform.unwrap_or_else(default_tldr_platform);
+    let page_path = resolve_tldr_page(&app, &platform, topic)?;
+    let raw_text = fs::read_to_string(&page_path)
+        .map_err(|error| format!("Failed to read tldr page {}: {error}", page_path.display()))?;
+
+    let (title, examples) = parse_tldr_page(&raw_text, topic);
+
+    Ok(ManDocumentPayload {
+        query: topic.to_string(),
+        title,
+        source: "tldr".to_string(),
+        raw_text,
+        fetched_at: current_timestamp(),
+        rendered_html: None,
+        references: Vec::new(),
+        examples,
     })
 }
 
+#[tauri::command]
+fn search_man(query: String) -> Result<Vec<ManSearchResult>, String> {
+    let query = query.trim();
+    if query.is_empty() {
+        return Err("Please provide a search term.".to_string());
+    }
+
+    let output = Command::new("apropos")
+        .arg(query)
+        .output()
+        .map_err(|error| format!("Failed to run apropos: {error}"))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
+        if output.stdout.is_empty() && (stderr.is_empty() || is_nothing_appropriate(&stderr)) {
+            return Ok(Vec::new());
+        }
+        return Err(stderr);
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let mut results = parse_apropos_output(&stdout);
+    results.sort_by(|a, b| a.section.cmp(&b.section).then_with(|| a.name.cmp(&b.name)));
+    results.truncate(MAX_SEARCH_RESULTS);
+
+    Ok(results)
+}
+
 #[tauri::command]
 fn get_settings(app: AppHandle) -> Result<ViewerSettings, String> {
     read_settings(&app)
@@ -167,6 +340,10 @@ fn is_section_token(token: &str) -> bool {
             .all(|character| character.is_ascii_alphanumeric() || character == '.')
 }
 
+fn is_man_not_found_error(stderr: &str) -> bool {
+    stderr.to_lowercase().contains("no manual entry")
+}
+
 fn run_man_command(section: Option<&str>, topic: &str) -> Result<String, String> {
     let mut command = Command::new("man");
 
@@ -186,8 +363,8 @@ fn run_man_command(section: Option<&str>, topic: &str) -> Result<String, String>
 
     if !output.status.success() {
         let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
-        if stderr.is_empty() {
-            return Err(format!("No manual entry found for `{topic}`."));
+        if stderr.is_empty() || is_man_not_found_error(&stderr) {
+            return Err(format!("No manual entry for `{topic}`."));
         }
         return Err(stderr);
     }
@@ -224,6 +401,265 @@ fn normalize_output_with_col(stdout: &[u8]) -> Result<String, String> {
     Ok(String::from_utf8_lossy(&output.stdout).replace('\u{8}', ""))
 }
 
+fn fetch_cheat_sheet(topic: &str) -> Result<ManDocumentPayload, String> {
+    let url = format!("https://cheat.sh/{topic}?T");
+    let agent = ureq::AgentBuilder::new()
+        .timeout(Duration::from_secs(CHEAT_SH_TIMEOUT_SECONDS))
+        .build();
+
+    let response = agent
+        .get(&url)
+        .call()
+        .map_err(|error| format!("Failed to reach cheat.sh: {error}"))?;
+
+    let body = response
+        .into_string()
+        .map_err(|error| format!("Failed to read cheat.sh response: {error}"))?;
+
+    let raw_text = strip_ansi_escapes(&body);
+
+    if raw_text.trim().is_empty() || raw_text.contains("Unknown topic.") {
+        return Err(format!("cheat.sh has no examples for `{topic}`."));
+    }
+
+    Ok(ManDocumentPayload {
+        query: topic.to_string(),
+        title: topic.to_uppercase(),
+        source: "cheat-sh".to_string(),
+        raw_text,
+        fetched_at: current_timestamp(),
+        rendered_html: None,
+        references: Vec::new(),
+        examples: Vec::new(),
+    })
+}
+
+fn strip_ansi_escapes(input: &str) -> String {
+    let pattern = Regex::new(r"\x1b\[[0-9;]*[A-Za-z]").expect("ANSI escape pattern is valid");
+    pattern.replace_all(input, "").to_string()
+}
+
+fn man_cache_dir(app: &AppHandle) -> Result<PathBuf, String> {
+    let cache_dir = app
+        .path()
+        .app_cache_dir()
+        .map_err(|error| format!("Failed to resolve cache directory: {error}"))?;
+
+    Ok(cache_dir.join("man-pages"))
+}
+
+fn man_cache_key(section: Option<&str>, topic: &str, render_mode: &str) -> String {
+    use std::hash::{Hash, Hasher};
+
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    section.unwrap_or_default().hash(&mut hasher);
+    topic.hash(&mut hasher);
+    render_mode.hash(&mut hasher);
+
+    format!("{:016x}", hasher.finish())
+}
+
+fn read_man_cache(app: &AppHandle, key: &str, ttl_seconds: u64) -> Option<ManDocumentPayload> {
+    let dir = man_cache_dir(app).ok()?;
+    let sidecar_path = dir.join(format!("{key}.meta.json"));
+    let body_path = dir.join(format!("{key}.json.gz"));
+
+    let sidecar_raw = fs::read_to_string(&sidecar_path).ok()?;
+    let sidecar: ManCacheSidecar = serde_json::from_str(&sidecar_raw).ok()?;
+    let fetched_at: u64 = sidecar.fetched_at.parse().ok()?;
+    let now: u64 = current_timestamp().parse().ok()?;
+
+    if now.saturating_sub(fetched_at) > ttl_seconds {
+        return None;
+    }
+
+    let compressed = fs::read(&body_path).ok()?;
+    let mut decoder = flate2::read::GzDecoder::new(&compressed[..]);
+    let mut json = String::new();
+    decoder.read_to_string(&mut json).ok()?;
+
+    serde_json::from_str(&json).ok()
+}
+
+fn write_man_cache(app: &AppHandle, key: &str, payload: &ManDocumentPayload) -> Result<(), String> {
+    let dir = man_cache_dir(app)?;
+    fs::create_dir_all(&dir)
+        .map_err(|error| format!("Failed to create man page cache directory: {error}"))?;
+
+    let json = serde_json::to_string(payload)
+        .map_err(|error| format!("Failed to serialize cached man page: {error}"))?;
+
+    let body_path = dir.join(format!("{key}.json.gz"));
+    let body_file = fs::File::create(&body_path)
+        .map_err(|error| format!("Failed to create {}: {error}", body_path.display()))?;
+    let mut encoder = flate2::write::GzEncoder::new(body_file, flate2::Compression::default());
+    encoder
+        .write_all(json.as_bytes())
+        .map_err(|error| format!("Failed to write man page cache entry: {error}"))?;
+    encoder
+        .finish()
+        .map_err(|error| format!("Failed to finish man page cache entry: {error}"))?;
+
+    let sidecar = ManCacheSidecar {
+        fetched_at: payload.fetched_at.clone(),
+    };
+    let sidecar_path = dir.join(format!("{key}.meta.json"));
+    let sidecar_json = serde_json::to_string(&sidecar)
+        .map_err(|error| format!("Failed to serialize cache sidecar: {error}"))?;
+    fs::write(&sidecar_path, sidecar_json)
+        .map_err(|error| format!("Failed to write {}: {error}", sidecar_path.display()))
+}
+
+fn render_man_html(section: Option<&str>, topic: &str) -> Result<String, String> {
+    let source_path = locate_man_source(section, topic)?;
+    let roff = read_possibly_gzipped(&source_path)?;
+    run_mandoc(&roff)
+}
+
+fn locate_man_source(section: Option<&str>, topic: &str) -> Result<PathBuf, String> {
+    let mut command = Command::new("man");
+    command.arg("-w");
+
+    if let Some(section) = section {
+        command.arg(section);
+    }
+
+    let output = command
+        .arg(topic)
+        .output()
+        .map_err(|error| format!("Failed to locate man page source: {error}"))?;
+
+    if !output.status.success() {
+        return Err(format!("Could not locate a source file for `{topic}`."));
+    }
+
+    let path = String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_string();
+
+    if path.is_empty() {
+        return Err(format!("Could not locate a source file for `{topic}`."));
+    }
+
+    Ok(PathBuf::from(path))
+}
+
+fn read_possibly_gzipped(path: &Path) -> Result<Vec<u8>, String> {
+    let bytes =
+        fs::read(path).map_err(|error| format!("Failed to read {}: {error}", path.display()))?;
+
+    if path.extension().and_then(|extension| extension.to_str()) == Some("gz") {
+        let mut decoder = flate2::read::GzDecoder::new(&bytes[..]);
+        let mut decompressed = Vec::new();
+        decoder
+            .read_to_end(&mut decompressed)
+            .map_err(|error| format!("Failed to decompress {}: {error}", path.display()))?;
+        Ok(decompressed)
+    } else {
+        Ok(bytes)
+    }
+}
+
+fn run_mandoc(roff: &[u8]) -> Result<String, String> {
+    let mut child = Command::new("mandoc")
+        .arg("-T")
+        .arg("html")
+        .arg("-O")
+        .arg("fragment")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|error| format!("Failed to start mandoc: {error}"))?;
+
+    if let Some(stdin) = child.stdin.as_mut() {
+        stdin
+            .write_all(roff)
+            .map_err(|error| format!("Failed to write roff source to mandoc: {error}"))?;
+    }
+
+    let output = child
+        .wait_with_output()
+        .map_err(|error| format!("Failed waiting on mandoc: {error}"))?;
+
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+}
+
+fn extract_references(raw_text: &str, topic: &str) -> Vec<ManReference> {
+    let pattern = Regex::new(r"\b([A-Za-z0-9_.+-]+)\(([0-9][a-z]?)\)")
+        .expect("cross-reference pattern is valid");
+
+    let mut seen = HashSet::new();
+    let mut references = Vec::new();
+
+    for captures in pattern.captures_iter(raw_text) {
+        let name = captures[1].to_string();
+        let section = captures[2].to_string();
+
+        if name.eq_ignore_ascii_case(topic) {
+            continue;
+        }
+
+        if seen.insert((name.to_lowercase(), section.clone())) {
+            references.push(ManReference { name, section });
+        }
+    }
+
+    references
+}
+
+fn is_nothing_appropriate(stderr: &str) -> bool {
+    stderr.to_lowercase().contains("nothing appropriate")
+}
+
+fn parse_apropos_output(stdout: &str) -> Vec<ManSearchResult> {
+    let pattern = Regex::new(
+        r"^(?P<names>[A-Za-z0-9_.+-]+(?:,\s*[A-Za-z0-9_.+-]+)*)\s*\((?P<section>[0-9][A-Za-z0-9]*)\)\s*-\s*(?P<summary>.+)$",
+    )
+    .expect("apropos pattern is valid");
+
+    let mut seen = HashSet::new();
+    let mut results = Vec::new();
+
+    for line in stdout.lines() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() {
+            continue;
+        }
+
+        let Some(captures) = pattern.captures(trimmed) else {
+            continue;
+        };
+
+        let section = captures["section"].to_string();
+        let summary = captures["summary"].trim().to_string();
+
+        for name in captures["names"].split(',') {
+            let name = name.trim().to_string();
+            if name.is_empty() {
+                continue;
+            }
+
+            if seen.insert((name.to_lowercase(), section.clone())) {
+                results.push(ManSearchResult {
+                    name,
+                    section: section.clone(),
+                    summary: summary.clone(),
+                });
+            }
+        }
+    }
+
+    results
+}
+
 fn extract_title(raw_text: &str, topic: &str) -> String {
     raw_text
         .lines()
@@ -233,6 +669,151 @@ fn extract_title(raw_text: &str, topic: &str) -> String {
         .unwrap_or_else(|| topic.to_uppercase())
 }
 
+fn default_tldr_platform() -> String {
+    match std::env::consts::OS {
+        "linux" => "linux",
+        "macos" => "osx",
+        _ => "common",
+    }
+    .to_string()
+}
+
+fn tldr_pages_dir(app: &AppHandle) -> Result<PathBuf, String> {
+    let cache_dir = app
+        .path()
+        .app_cache_dir()
+        .map_err(|error| format!("Failed to resolve cache directory: {error}"))?;
+
+    Ok(cache_dir.join("tldr").join("pages"))
+}
+
+fn resolve_tldr_page(app: &AppHandle, platform: &str, topic: &str) -> Result<PathBuf, String> {
+    let pages_dir = tldr_pages_dir(app)?;
+
+    if let Some(page) = find_tldr_page(&pages_dir, platform, topic) {
+        return Ok(page);
+    }
+
+    let tldr_dir = pages_dir
+        .parent()
+        .ok_or_else(|| "Failed to resolve tldr cache directory.".to_string())?;
+
+    if !tldr_fetched_marker(tldr_dir).exists() {
+        download_tldr_pages(app, &pages_dir)?;
+    }
+
+    find_tldr_page(&pages_dir, platform, topic)
+        .ok_or_else(|| format!("No tldr page found for `{topic}`."))
+}
+
+fn tldr_fetched_marker(tldr_dir: &Path) -> PathBuf {
+    tldr_dir.join(".fetched")
+}
+
+fn find_tldr_page(pages_dir: &Path, platform: &str, topic: &str) -> Option<PathBuf> {
+    for candidate_platform in [platform, "common"] {
+        let candidate = pages_dir
+            .join(candidate_platform)
+            .join(format!("{topic}.md"));
+        if candidate.exists() {
+            return Some(candidate);
+        }
+    }
+
+    None
+}
+
+fn download_tldr_pages(app: &AppHandle, pages_dir: &Path) -> Result<(), String> {
+    let tldr_dir = pages_dir
+        .parent()
+        .ok_or_else(|| "Failed to resolve tldr cache directory.".to_string())?;
+
+    fs::create_dir_all(tldr_dir)
+        .map_err(|error| format!("Failed to create tldr cache directory: {error}"))?;
+
+    let response =
+        ureq::get("https://github.com/tldr-pages/tldr/releases/latest/download/tldr.zip")
+            .call()
+            .map_err(|error| format!("Failed to download tldr pages archive: {error}"))?;
+
+    let mut archive_bytes = Vec::new();
+    response
+        .into_reader()
+        .read_to_end(&mut archive_bytes)
+        .map_err(|error| format!("Failed to read tldr pages archive: {error}"))?;
+
+    let archive_path = tldr_dir.join("tldr.zip");
+    fs::write(&archive_path, &archive_bytes)
+        .map_err(|error| format!("Failed to save tldr pages archive: {error}"))?;
+
+    let archive_file = fs::File::open(&archive_path)
+        .map_err(|error| format!("Failed to open tldr pages archive: {error}"))?;
+    let mut archive = zip::ZipArchive::new(archive_file)
+        .map_err(|error| format!("Failed to read tldr pages archive: {error}"))?;
+
+    for index in 0..archive.len() {
+        let mut entry = archive
+            .by_index(index)
+            .map_err(|error| format!("Failed to read tldr archive entry: {error}"))?;
+
+        let Some(relative_path) = entry.enclosed_name().map(Path::to_path_buf) else {
+            continue;
+        };
+
+        if !relative_path.starts_with("pages") {
+            continue;
+        }
+
+        let out_path = tldr_dir.join(&relative_path);
+
+        if entry.is_dir() {
+            fs::create_dir_all(&out_path)
+                .map_err(|error| format!("Failed to create {}: {error}", out_path.display()))?;
+            continue;
+        }
+
+        if let Some(parent) = out_path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|error| format!("Failed to create {}: {error}", parent.display()))?;
+        }
+
+        let mut out_file = fs::File::create(&out_path)
+            .map_err(|error| format!("Failed to create {}: {error}", out_path.display()))?;
+        std::io::copy(&mut entry, &mut out_file)
+            .map_err(|error| format!("Failed to extract {}: {error}", out_path.display()))?;
+    }
+
+    fs::write(tldr_fetched_marker(tldr_dir), "")
+        .map_err(|error| format!("Failed to record tldr pages fetch: {error}"))?;
+
+    Ok(())
+}
+
+fn parse_tldr_page(raw_text: &str, topic: &str) -> (String, Vec<TldrExample>) {
+    let mut title = topic.to_uppercase();
+    let mut examples = Vec::new();
+    let mut pending_description: Option<String> = None;
+
+    for line in raw_text.lines() {
+        let trimmed = line.trim();
+
+        if let Some(heading) = trimmed.strip_prefix("# ") {
+            title = heading.trim().to_string();
+        } else if let Some(description) = trimmed.strip_prefix('-') {
+            pending_description = Some(description.trim().trim_end_matches(':').to_string());
+        } else if trimmed.starts_with('`') && trimmed.ends_with('`') && trimmed.len() > 1 {
+            if let Some(description) = pending_description.take() {
+                examples.push(TldrExample {
+                    description,
+                    command: trimmed.trim_matches('`').to_string(),
+                });
+            }
+        }
+    }
+
+    (title, examples)
+}
+
 fn settings_path(app: &AppHandle) -> Result<PathBuf, String> {
     let config_dir = app
         .path()
@@ -252,8 +833,12 @@ fn read_settings(app: &AppHandle) -> Result<ViewerSettings, String> {
         return Ok(ViewerSettings::default());
     }
 
-    let raw = fs::read_to_string(&path)
-        .map_err(|error| format!("Failed to read viewer settings from {}: {error}", path.display()))?;
+    let raw = fs::read_to_string(&path).map_err(|error| {
+        format!(
+            "Failed to read viewer settings from {}: {error}",
+            path.display()
+        )
+    })?;
 
     let parsed = serde_json::from_str::<ViewerSettings>(&raw).unwrap_or_default();
     Ok(sanitized_settings(parsed))
@@ -294,6 +879,22 @@ fn merge_settings(current: &mut ViewerSettings, patch: ViewerSettingsPatch) {
         };
     }
 
+    if let Some(html_rendering) = patch.html_rendering {
+        current.html_rendering = html_rendering;
+    }
+
+    if let Some(cache_enabled) = patch.cache_enabled {
+        current.cache_enabled = cache_enabled;
+    }
+
+    if let Some(cache_ttl_seconds) = patch.cache_ttl_seconds {
+        current.cache_ttl_seconds = cache_ttl_seconds.max(MIN_CACHE_TTL_SECONDS);
+    }
+
+    if let Some(cheat_sh_fallback) = patch.cheat_sh_fallback {
+        current.cheat_sh_fallback = cheat_sh_fallback;
+    }
+
     if let Some(window_state) = patch.window_state {
         if let Some(width) = window_state.width {
             current.window_state.width = width.max(640.0);
@@ -330,6 +931,10 @@ fn sanitized_settings(input: ViewerSettings) -> ViewerSettings {
         } else {
             "find".to_string()
         },
+        html_rendering: input.html_rendering,
+        cache_enabled: input.cache_enabled,
+        cache_ttl_seconds: input.cache_ttl_seconds.max(MIN_CACHE_TTL_SECONDS),
+        cheat_sh_fallback: input.cheat_sh_fallback,
         window_state: WindowState {
             width: input.window_state.width.max(640.0),
             height: input.window_state.height.max(420.0),
@@ -340,12 +945,104 @@ fn sanitized_settings(input: ViewerSettings) -> ViewerSettings {
     }
 }
 
+const WINDOW_STATE_DEBOUNCE_MS: u64 = 400;
+
+fn apply_window_state(app: &AppHandle, window: &tauri::WebviewWindow) {
+    let Ok(settings) = read_settings(app) else {
+        return;
+    };
+    let state = &settings.window_state;
+
+    let _ = window.set_size(tauri::LogicalSize::new(state.width, state.height));
+
+    if let (Some(x), Some(y)) = (state.x, state.y) {
+        let _ = window.set_position(tauri::LogicalPosition::new(x, y));
+    }
+
+    if state.maximized == Some(true) {
+        let _ = window.maximize();
+    }
+}
+
+fn watch_window_state(app: AppHandle, window: tauri::WebviewWindow) {
+    let generation = Arc::new(AtomicU64::new(0));
+
+    let watched_app = app.clone();
+    let watched_window = window.clone();
+
+    window.clone().on_window_event(move |event| match event {
+        tauri::WindowEvent::Resized(_) | tauri::WindowEvent::Moved(_) => {
+            schedule_window_state_save(watched_app.clone(), watched_window.clone(), &generation);
+        }
+        tauri::WindowEvent::CloseRequested { .. } => {
+            save_window_state(&watched_app, &watched_window);
+        }
+        _ => {}
+    });
+}
+
+fn schedule_window_state_save(
+    app: AppHandle,
+    window: tauri::WebviewWindow,
+    generation: &Arc<AtomicU64>,
+) {
+    let generation = generation.clone();
+    let this_generation = generation.fetch_add(1, Ordering::SeqCst) + 1;
+
+    std::thread::spawn(move || {
+        std::thread::sleep(Duration::from_millis(WINDOW_STATE_DEBOUNCE_MS));
+        if generation.load(Ordering::SeqCst) == this_generation {
+            save_window_state(&app, &window);
+        }
+    });
+}
+
+fn save_window_state(app: &AppHandle, window: &tauri::WebviewWindow) {
+    let Ok(mut settings) = read_settings(app) else {
+        return;
+    };
+
+    let is_maximized = window.is_maximized().unwrap_or(false);
+
+    if !is_maximized {
+        if let Ok(scale_factor) = window.scale_factor() {
+            if let Ok(size) = window.inner_size() {
+                let logical = size.to_logical::<f64>(scale_factor);
+                settings.window_state.width = logical.width;
+                settings.window_state.height = logical.height;
+            }
+
+            if let Ok(position) = window.outer_position() {
+                let logical = position.to_logical::<f64>(scale_factor);
+                settings.window_state.x = Some(logical.x);
+                settings.window_state.y = Some(logical.y);
+            }
+        }
+    }
+
+    settings.window_state.maximized = Some(is_maximized);
+
+    let _ = write_settings(app, &settings);
+}
+
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
     tauri::Builder::default()
         .plugin(tauri_plugin_opener::init())
+        .setup(|app| {
+            if let Some(window) = app.get_webview_window("main") {
+                let handle = app.handle().clone();
+                apply_window_state(&handle, &window);
+                watch_window_state(handle, window);
+            }
+            Ok(())
+        })
         .invoke_handler(tauri::generate_handler![
             load_man_page,
+            load_tldr_page,
+            search_man,
+            load_cheat_sheet,
+            clear_man_cache,
             get_settings,
             set_settings,
             suggest_alias